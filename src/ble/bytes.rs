@@ -2,13 +2,27 @@
 //!
 //! The most important parts offered by this module are [`ToBytes`] and [`FromBytes`],
 //! general-purpose traits for zero-allocation (de)serialization from/to bytes. These are used all
-//! over the place in Rubble, along with [`ByteWriter`]. In addition to those, this module also
-//! defines helpful extension traits on `&[T]` and `&mut [T]`, defined on [`SliceExt`] and
-//! [`MutSliceExt`], and on `&[u8]`, defined on [`BytesExt`].
+//! over the place in Rubble, along with [`ByteWriter`] and its read-side counterpart
+//! [`ByteReader`]. [`ToBytes`] is generic over the [`Writer`] trait, which [`ByteWriter`] and
+//! (behind the `alloc` feature) `Vec<u8>` both implement; [`Chain`], [`Limit`], and [`Take`] are
+//! adapters for spreading a write across two buffers and for capping how much can be written or
+//! read. [`write_length_prefixed`] automates the reserve/backfill pattern used by BLE's
+//! length-prefixed structures. [`Pod`] and the `read_pod`/`read_pod_slice` methods on
+//! [`BytesExt`] allow decoding fixed-layout structs without copying. In addition to those, this
+//! module also defines helpful extension traits on `&[T]` and `&mut [T]`, defined on [`SliceExt`]
+//! and [`MutSliceExt`], and on `&[u8]`, defined on [`BytesExt`]. Behind the `std` feature,
+//! [`ByteWriter`] and [`ByteReader`] also implement `std::io::{Write, Read}`.
 //!
 //! [`ToBytes`]: trait.ToBytes.html
 //! [`FromBytes`]: trait.FromBytes.html
 //! [`ByteWriter`]: struct.ByteWriter.html
+//! [`ByteReader`]: struct.ByteReader.html
+//! [`Writer`]: trait.Writer.html
+//! [`Chain`]: struct.Chain.html
+//! [`Limit`]: struct.Limit.html
+//! [`Take`]: struct.Take.html
+//! [`write_length_prefixed`]: struct.ByteWriter.html#method.write_length_prefixed
+//! [`Pod`]: trait.Pod.html
 //! [`SliceExt`]: trait.SliceExt.html
 //! [`MutSliceExt`]: trait.MutSliceExt.html
 //! [`BytesExt`]: trait.BytesExt.html
@@ -16,9 +30,12 @@
 use {
     crate::ble::Error,
     byteorder::ByteOrder,
-    core::{fmt, iter, mem},
+    core::{convert::TryFrom, fmt, iter, mem, slice},
 };
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub use byteorder::LittleEndian;
 
 /// Reference to a `T`, or to a byte slice that can be decoded as a `T`.
@@ -123,7 +140,7 @@ impl<'a, T: FromBytes<'a>> FromBytes<'a> for BytesOr<'a, [T]> {
 }
 
 impl<'a, T: ToBytes> ToBytes for BytesOr<'a, T> {
-    fn to_bytes(&self, buffer: &mut ByteWriter) -> Result<(), Error> {
+    fn to_bytes<W: Writer>(&self, buffer: &mut W) -> Result<(), Error> {
         match self.0 {
             Inner::Bytes(b) => buffer.write_slice(b),
             Inner::Or(t) => t.to_bytes(buffer),
@@ -132,7 +149,7 @@ impl<'a, T: ToBytes> ToBytes for BytesOr<'a, T> {
 }
 
 impl<'a, T: ToBytes> ToBytes for BytesOr<'a, [T]> {
-    fn to_bytes(&self, buffer: &mut ByteWriter) -> Result<(), Error> {
+    fn to_bytes<W: Writer>(&self, buffer: &mut W) -> Result<(), Error> {
         match self.0 {
             Inner::Bytes(b) => buffer.write_slice(b),
             Inner::Or(ts) => {
@@ -299,14 +316,474 @@ impl<'a> ByteWriter<'a> {
         }
     }
 
-    /// Writes a `u16` to `self`, using byte order `B`.
+    /// Splits off the next byte in the buffer.
     ///
-    /// If `self` does not have enough space left, an error will be returned and no bytes will be
-    /// written to `self`.
-    pub fn write_u16<'b, B: ByteOrder>(&'b mut self, value: u16) -> Result<(), Error>
+    /// The writer will be advanced to point to the rest of the underlying buffer.
+    ///
+    /// This allows filling in the value of the byte later, after writing more data.
+    ///
+    /// For a similar, but more flexible operation, see [`split_off`].
+    ///
+    /// [`split_off`]: #method.split_off
+    pub fn split_next_mut<'b>(&'b mut self) -> Option<&'a mut u8>
     where
         'a: 'b,
     {
+        let this = mem::replace(&mut self.0, &mut []);
+        // Slight contortion to please the borrow checker:
+        if this.is_empty() {
+            self.0 = this;
+            None
+        } else {
+            let (first, rest) = this.split_first_mut().unwrap();
+            self.0 = rest;
+            Some(first)
+        }
+    }
+
+    /// Chains `self` with `other`, returning a [`Writer`] that writes to `self` until it is full,
+    /// then spills any remaining writes into `other`.
+    ///
+    /// [`Writer`]: trait.Writer.html
+    pub fn chain(self, other: ByteWriter<'a>) -> Chain<'a> {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Limits `self` to accepting at most `limit` more bytes.
+    ///
+    /// Writes past `limit` will return `Error::Eof`, even if `self`'s underlying buffer has more
+    /// space left.
+    pub fn limit(self, limit: usize) -> Limit<Self> {
+        Limit { inner: self, limit }
+    }
+
+    /// Reserves `width` bytes for a length field, runs `f` against the rest of `self`, then
+    /// backfills the reserved field with the number of bytes `f` wrote, in byte order `B`.
+    ///
+    /// This automates the reserve-write-backfill pattern used by the many length-prefixed
+    /// structures in BLE (the L2CAP length field, AD structure length bytes, ATT value lengths),
+    /// built out of [`split_off`] and the primitive `write_*` methods.
+    ///
+    /// Returns `Error::Eof` if there is no space left for the length field or if the number of
+    /// bytes `f` wrote does not fit into `width`. If `f` fails, its error is returned unchanged. On
+    /// any of these errors, `self` is left unmodified, as with the other fallible methods on this
+    /// type.
+    ///
+    /// [`split_off`]: #method.split_off
+    pub fn write_length_prefixed<B, F>(&mut self, width: LenWidth, f: F) -> Result<(), Error>
+    where
+        B: ByteOrder,
+        F: FnOnce(&mut ByteWriter) -> Result<(), Error>,
+    {
+        let buf = mem::replace(&mut self.0, &mut []);
+        let ptr = buf.as_mut_ptr();
+        let total_len = buf.len();
+
+        let result = (|| {
+            if total_len < width.num_bytes() {
+                return Err(Error::Eof);
+            }
+            let (len_bytes, rest) = buf.split_at_mut(width.num_bytes());
+
+            let mut writer = ByteWriter::new(rest);
+            let before = writer.space_left();
+            f(&mut writer)?;
+            let written = before - writer.space_left();
+
+            let mut len_writer = ByteWriter::new(len_bytes);
+            match width {
+                LenWidth::U8 => {
+                    let len = u8::try_from(written).map_err(|_| Error::Eof)?;
+                    len_writer.write_byte(len)?;
+                }
+                LenWidth::U16 => {
+                    let len = u16::try_from(written).map_err(|_| Error::Eof)?;
+                    len_writer.write_u16::<B>(len)?;
+                }
+                LenWidth::U32 => {
+                    let len = u32::try_from(written).map_err(|_| Error::Eof)?;
+                    len_writer.write_u32::<B>(len)?;
+                }
+            }
+            Ok(width.num_bytes() + written)
+        })();
+
+        match result {
+            Ok(consumed) => {
+                // SAFETY: `consumed` is `width.num_bytes()` plus however many bytes `writer` (a
+                // `ByteWriter` over the tail of `buf`, starting at `ptr`) reported as written, so
+                // `ptr.add(consumed)` together with the remaining `total_len - consumed` bytes is
+                // exactly the unwritten tail of the original buffer.
+                self.0 = unsafe { slice::from_raw_parts_mut(ptr.add(consumed), total_len - consumed) };
+                Ok(())
+            }
+            Err(e) => {
+                // SAFETY: `ptr`/`total_len` describe exactly the buffer `self` wrapped before this
+                // call. Restoring them on failure keeps `self` unmodified, as `write_slice`,
+                // `split_off`, and `skip` all guarantee on their own error paths.
+                self.0 = unsafe { slice::from_raw_parts_mut(ptr, total_len) };
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Width, in bytes, of a length field reserved by [`ByteWriter::write_length_prefixed`].
+///
+/// [`ByteWriter::write_length_prefixed`]: struct.ByteWriter.html#method.write_length_prefixed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LenWidth {
+    /// A single-byte length field (eg. the length byte of a BLE AD structure).
+    U8,
+    /// A 2-byte length field (eg. the length field of an L2CAP PDU).
+    U16,
+    /// A 4-byte length field.
+    U32,
+}
+
+impl LenWidth {
+    fn num_bytes(self) -> usize {
+        match self {
+            LenWidth::U8 => 1,
+            LenWidth::U16 => 2,
+            LenWidth::U32 => 4,
+        }
+    }
+}
+
+/// Adapter that writes to one [`ByteWriter`] until it is full, then spills the remaining writes
+/// into a second one.
+///
+/// Created by [`ByteWriter::chain`]. This is useful to spread one logical encoding across
+/// discontiguous buffers (eg. a header buffer followed by a payload buffer).
+///
+/// [`ByteWriter`]: struct.ByteWriter.html
+/// [`ByteWriter::chain`]: struct.ByteWriter.html#method.chain
+pub struct Chain<'a> {
+    first: ByteWriter<'a>,
+    second: ByteWriter<'a>,
+}
+
+impl<'a> Chain<'a> {
+    /// Consumes `self`, returning the unwritten parts of both underlying buffers.
+    pub fn into_rest(self) -> (&'a mut [u8], &'a mut [u8]) {
+        (self.first.into_rest(), self.second.into_rest())
+    }
+}
+
+impl<'a> Writer for Chain<'a> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), Error> {
+        if self.first.space_left() == 0 {
+            self.second.write_byte(byte)
+        } else {
+            self.first.write_byte(byte)
+        }
+    }
+
+    fn write_slice(&mut self, other: &[u8]) -> Result<(), Error> {
+        let avail = self.first.space_left();
+        if other.len() > avail {
+            let (head, tail) = other.split_at(avail);
+            if tail.len() > self.second.space_left() {
+                // Neither buffer is touched unless `other` is known to fit in both combined, so
+                // that a failing call leaves `self` unmodified, as `Writer::write_slice` promises.
+                return Err(Error::Eof);
+            }
+            self.first.write_slice(head)?;
+            self.second.write_slice(tail)
+        } else {
+            self.first.write_slice(other)
+        }
+    }
+}
+
+/// Adapter that limits the number of bytes that can be written to an underlying [`Writer`].
+///
+/// Created by [`ByteWriter::limit`].
+///
+/// [`Writer`]: trait.Writer.html
+/// [`ByteWriter::limit`]: struct.ByteWriter.html#method.limit
+pub struct Limit<W> {
+    inner: W,
+    limit: usize,
+}
+
+impl<W> Limit<W> {
+    /// Returns the number of bytes that can still be written before the limit is hit.
+    pub fn limit_left(&self) -> usize {
+        self.limit
+    }
+
+    /// Consumes `self`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Writer> Writer for Limit<W> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), Error> {
+        if self.limit == 0 {
+            return Err(Error::Eof);
+        }
+        self.inner.write_byte(byte)?;
+        self.limit -= 1;
+        Ok(())
+    }
+
+    fn write_slice(&mut self, other: &[u8]) -> Result<(), Error> {
+        if other.len() > self.limit {
+            return Err(Error::Eof);
+        }
+        self.inner.write_slice(other)?;
+        self.limit -= other.len();
+        Ok(())
+    }
+}
+
+/// Cursor into a byte slice that can be used to decode data from bytes.
+///
+/// This is the read-side counterpart to [`ByteWriter`]. Where parsing code would otherwise thread
+/// a `&mut &'a [u8]` through [`FromBytes`] and the [`BytesExt`]/[`SliceExt`] helpers by hand,
+/// `ByteReader` tracks how much has been consumed and makes it easy to bound a read to a
+/// sub-region of the buffer via [`split_off`].
+///
+/// [`ByteWriter`]: struct.ByteWriter.html
+/// [`FromBytes`]: trait.FromBytes.html
+/// [`BytesExt`]: trait.BytesExt.html
+/// [`SliceExt`]: trait.SliceExt.html
+/// [`split_off`]: #method.split_off
+pub struct ByteReader<'a>(&'a [u8]);
+
+impl<'a> ByteReader<'a> {
+    /// Creates a reader that will read from `buf`.
+    pub fn new(buf: &'a [u8]) -> Self {
+        ByteReader(buf)
+    }
+
+    /// Returns the number of bytes that have not yet been read.
+    pub fn bytes_left(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the raw, unread part of the underlying byte slice.
+    ///
+    /// This does not advance `self`.
+    pub fn rest(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Returns the next byte without advancing `self`.
+    ///
+    /// Returns `Error::Eof` if `self` is empty.
+    pub fn peek(&self) -> Result<u8, Error> {
+        self.0.first().copied().ok_or(Error::Eof)
+    }
+
+    /// Skips the given number of bytes without returning them.
+    ///
+    /// Returns `Error::Eof` if `self` does not contain that many bytes, in which case `self` will
+    /// not be modified.
+    pub fn skip(&mut self, bytes: usize) -> Result<(), Error> {
+        self.read_slice(bytes).map(drop)
+    }
+
+    /// Reads a slice of `len` bytes out of `self`.
+    ///
+    /// `self` will be updated to point past the extracted bytes.
+    ///
+    /// If `self` does not contain `len` bytes, `Error::Eof` will be returned and `self` will not
+    /// be modified.
+    pub fn read_slice(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        self.0.read_slice(len)
+    }
+
+    /// Reads an array-like type `S` out of `self`.
+    ///
+    /// `self` will be updated to point past the read data.
+    ///
+    /// If `self` doesn't contain enough bytes to fill an `S`, returns `Error::Eof` without
+    /// changing `self`.
+    pub fn read_array<S>(&mut self) -> Result<S, Error>
+    where
+        S: Default + AsMut<[u8]>,
+    {
+        self.0.read_array()
+    }
+
+    /// Reads a `u8` from `self`.
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        self.0.read_u8()
+    }
+
+    /// Reads a `u16` from `self`, using byte order `B`.
+    pub fn read_u16<B: ByteOrder>(&mut self) -> Result<u16, Error> {
+        self.0.read_u16::<B>()
+    }
+
+    /// Reads a `u32` from `self`, using byte order `B`.
+    pub fn read_u32<B: ByteOrder>(&mut self) -> Result<u32, Error> {
+        self.0.read_u32::<B>()
+    }
+
+    /// Reads a `u64` from `self`, using byte order `B`.
+    pub fn read_u64<B: ByteOrder>(&mut self) -> Result<u64, Error> {
+        self.0.read_u64::<B>()
+    }
+
+    /// Reads a `T` out of `self` by delegating to its `FromBytes` implementation.
+    ///
+    /// `self` will be updated to point past the data that was read.
+    pub fn read<T: FromBytes<'a>>(&mut self) -> Result<T, Error> {
+        T::from_bytes(&mut self.0)
+    }
+
+    /// Creates and returns another `ByteReader` bounded to the next `len` bytes in the buffer.
+    ///
+    /// `self` will be modified to point after the split-off bytes.
+    ///
+    /// Mirrors [`ByteWriter::split_off`] on the read side.
+    ///
+    /// [`ByteWriter::split_off`]: struct.ByteWriter.html#method.split_off
+    #[must_use]
+    pub fn split_off(&mut self, len: usize) -> Result<Self, Error> {
+        Ok(ByteReader::new(self.read_slice(len)?))
+    }
+
+    /// Limits `self` to reading at most `limit` more bytes.
+    ///
+    /// Reads past `limit` will return `Error::Eof`, even if `self` has more data left.
+    pub fn take(self, limit: usize) -> Take<'a> {
+        Take { inner: self, limit }
+    }
+}
+
+/// Adapter that limits the number of bytes that can be read from an underlying [`ByteReader`].
+///
+/// Created by [`ByteReader::take`]. Exposes `ByteReader`'s `peek`, `skip`, `read_slice`, `read_u*`
+/// and `read` methods (but not `read_array`, `split_off` or `take`), each returning `Error::Eof`
+/// once the limit is hit, even if the underlying reader has more data left.
+///
+/// [`ByteReader`]: struct.ByteReader.html
+/// [`ByteReader::take`]: struct.ByteReader.html#method.take
+pub struct Take<'a> {
+    inner: ByteReader<'a>,
+    limit: usize,
+}
+
+impl<'a> Take<'a> {
+    /// Returns the number of bytes that can still be read before the limit is hit.
+    pub fn limit_left(&self) -> usize {
+        self.limit
+    }
+
+    /// Consumes `self`, returning the underlying reader.
+    pub fn into_inner(self) -> ByteReader<'a> {
+        self.inner
+    }
+
+    /// Returns the number of bytes that have not yet been read, bounded by the limit.
+    pub fn bytes_left(&self) -> usize {
+        self.inner.bytes_left().min(self.limit)
+    }
+
+    /// Returns the next byte without advancing `self`.
+    ///
+    /// Returns `Error::Eof` if the limit has been reached or `self` is empty.
+    pub fn peek(&self) -> Result<u8, Error> {
+        if self.limit == 0 {
+            return Err(Error::Eof);
+        }
+        self.inner.peek()
+    }
+
+    /// Skips the given number of bytes without returning them, respecting the limit.
+    ///
+    /// Returns `Error::Eof` if `self` does not contain that many bytes, in which case `self` will
+    /// not be modified.
+    pub fn skip(&mut self, bytes: usize) -> Result<(), Error> {
+        self.read_slice(bytes).map(drop)
+    }
+
+    /// Reads a slice of `len` bytes out of `self`, respecting the limit.
+    ///
+    /// `self` will be updated to point past the extracted bytes.
+    ///
+    /// Returns `Error::Eof` if `len` exceeds the limit or the number of bytes left in the
+    /// underlying reader, in which case `self` will not be modified.
+    pub fn read_slice(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        if len > self.limit {
+            return Err(Error::Eof);
+        }
+        let slice = self.inner.read_slice(len)?;
+        self.limit -= len;
+        Ok(slice)
+    }
+
+    /// Reads a `u8` from `self`, respecting the limit.
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    /// Reads a `u16` from `self`, using byte order `B`, respecting the limit.
+    pub fn read_u16<B: ByteOrder>(&mut self) -> Result<u16, Error> {
+        Ok(B::read_u16(self.read_slice(2)?))
+    }
+
+    /// Reads a `u32` from `self`, using byte order `B`, respecting the limit.
+    pub fn read_u32<B: ByteOrder>(&mut self) -> Result<u32, Error> {
+        Ok(B::read_u32(self.read_slice(4)?))
+    }
+
+    /// Reads a `u64` from `self`, using byte order `B`, respecting the limit.
+    pub fn read_u64<B: ByteOrder>(&mut self) -> Result<u64, Error> {
+        Ok(B::read_u64(self.read_slice(8)?))
+    }
+
+    /// Reads a `T` out of `self` by delegating to its `FromBytes` implementation, respecting the
+    /// limit.
+    ///
+    /// `self` will be updated to point past the data that was read. Returns `Error::Eof` without
+    /// consuming anything if `T` would need to read past the limit.
+    pub fn read<T: FromBytes<'a>>(&mut self) -> Result<T, Error> {
+        let capped = &self.inner.rest()[..self.bytes_left()];
+        let mut cursor = capped;
+        let value = T::from_bytes(&mut cursor)?;
+        let consumed = capped.len() - cursor.len();
+        self.inner.skip(consumed)?;
+        self.limit -= consumed;
+        Ok(value)
+    }
+}
+
+/// A sink that bytes can be encoded into.
+///
+/// This abstracts over the destination [`ToBytes`] encodes into. [`ByteWriter`] is the "classic"
+/// implementor, backed by a fixed `&mut [u8]` that returns `Error::Eof` once full. Behind the
+/// `alloc` feature, `Vec<u8>` also implements `Writer`, growing to fit whatever is written to it,
+/// which is useful when the final encoded size isn't known up front.
+///
+/// [`ToBytes`]: trait.ToBytes.html
+/// [`ByteWriter`]: struct.ByteWriter.html
+pub trait Writer {
+    /// Writes a single byte to `self`.
+    ///
+    /// Returns `Error::Eof` when no space is left.
+    fn write_byte(&mut self, byte: u8) -> Result<(), Error>;
+
+    /// Writes all bytes from `other` to `self`.
+    ///
+    /// Returns `Error::Eof` when `self` does not have enough space left to fit `other`. In that
+    /// case, `self` will not be modified.
+    fn write_slice(&mut self, other: &[u8]) -> Result<(), Error>;
+
+    /// Writes a `u16` to `self`, using byte order `B`.
+    ///
+    /// If `self` does not have enough space left, an error will be returned and no bytes will be
+    /// written to `self`.
+    fn write_u16<B: ByteOrder>(&mut self, value: u16) -> Result<(), Error> {
         let mut bytes = [0; 2];
         B::write_u16(&mut bytes, value);
         self.write_slice(&bytes)
@@ -316,10 +793,7 @@ impl<'a> ByteWriter<'a> {
     ///
     /// If `self` does not have enough space left, an error will be returned and no bytes will be
     /// written to `self`.
-    pub fn write_u32<'b, B: ByteOrder>(&'b mut self, value: u32) -> Result<(), Error>
-    where
-        'a: 'b,
-    {
+    fn write_u32<B: ByteOrder>(&mut self, value: u32) -> Result<(), Error> {
         let mut bytes = [0; 4];
         B::write_u32(&mut bytes, value);
         self.write_slice(&bytes)
@@ -329,49 +803,44 @@ impl<'a> ByteWriter<'a> {
     ///
     /// If `self` does not have enough space left, an error will be returned and no bytes will be
     /// written to `self`.
-    pub fn write_u64<'b, B: ByteOrder>(&'b mut self, value: u64) -> Result<(), Error>
-    where
-        'a: 'b,
-    {
+    fn write_u64<B: ByteOrder>(&mut self, value: u64) -> Result<(), Error> {
         let mut bytes = [0; 8];
         B::write_u64(&mut bytes, value);
         self.write_slice(&bytes)
     }
+}
 
-    /// Splits off the next byte in the buffer.
-    ///
-    /// The writer will be advanced to point to the rest of the underlying buffer.
-    ///
-    /// This allows filling in the value of the byte later, after writing more data.
-    ///
-    /// For a similar, but more flexible operation, see [`split_off`].
-    ///
-    /// [`split_off`]: #method.split_off
-    pub fn split_next_mut<'b>(&'b mut self) -> Option<&'a mut u8>
-    where
-        'a: 'b,
-    {
-        let this = mem::replace(&mut self.0, &mut []);
-        // Slight contortion to please the borrow checker:
-        if this.is_empty() {
-            self.0 = this;
-            None
-        } else {
-            let (first, rest) = this.split_first_mut().unwrap();
-            self.0 = rest;
-            Some(first)
-        }
+impl<'a> Writer for ByteWriter<'a> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), Error> {
+        ByteWriter::write_byte(self, byte)
+    }
+
+    fn write_slice(&mut self, other: &[u8]) -> Result<(), Error> {
+        ByteWriter::write_slice(self, other)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Writer for alloc::vec::Vec<u8> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), Error> {
+        self.push(byte);
+        Ok(())
+    }
+
+    fn write_slice(&mut self, other: &[u8]) -> Result<(), Error> {
+        self.extend_from_slice(other);
+        Ok(())
     }
 }
 
 /// Trait for encoding a value into a byte buffer.
 pub trait ToBytes {
-    /// Converts `self` to bytes and writes them into `buffer`, advancing `buffer` to point past the
-    /// encoded value.
+    /// Converts `self` to bytes and writes them into `writer`, advancing `writer` to point past
+    /// the encoded value.
     ///
-    /// If `buffer` does not contain enough space, an error will be returned and the state of the
-    /// buffer is unspecified (eg. `self` may be partially written into `buffer`).
-    fn to_bytes(&self, writer: &mut ByteWriter) -> Result<(), Error>;
+    /// If `writer` does not contain enough space, an error will be returned and the state of the
+    /// writer is unspecified (eg. `self` may be partially written into `writer`).
+    fn to_bytes<W: Writer>(&self, writer: &mut W) -> Result<(), Error>;
 }
 
 /// Trait for decoding values from a byte slice.
@@ -385,13 +854,13 @@ pub trait FromBytes<'a>: Sized {
 }
 
 impl ToBytes for [u8] {
-    fn to_bytes(&self, writer: &mut ByteWriter) -> Result<(), Error> {
+    fn to_bytes<W: Writer>(&self, writer: &mut W) -> Result<(), Error> {
         writer.write_slice(self)
     }
 }
 
 impl<'a> ToBytes for &'a [u8] {
-    fn to_bytes(&self, writer: &mut ByteWriter) -> Result<(), Error> {
+    fn to_bytes<W: Writer>(&self, writer: &mut W) -> Result<(), Error> {
         writer.write_slice(*self)
     }
 }
@@ -408,12 +877,59 @@ impl<'a> FromBytes<'a> for u8 {
     }
 }
 
+/// Marker trait for types that can be decoded from, or viewed as, a byte slice without copying.
+///
+/// BLE is little-endian, and so is every platform Rubble targets, so a `Pod` type's in-memory
+/// representation matches the bytes Rubble parses and emits.
+///
+/// # Safety
+///
+/// Implementors must guarantee that `Self` has no padding bytes and no invalid bit patterns (ie.
+/// every possible arrangement of `size_of::<Self>()` bytes is a valid value of `Self`).
+pub unsafe trait Pod: Copy {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for i8 {}
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Returns the byte representation of a `Pod` value, without copying.
+pub fn bytes_of<T: Pod>(value: &T) -> &[u8] {
+    // SAFETY: `T: Pod` guarantees that `value` has no padding bytes, so viewing it as a byte slice
+    // of its size is always valid.
+    unsafe { slice::from_raw_parts(value as *const T as *const u8, mem::size_of::<T>()) }
+}
+
 /// Extensions on `&'a [u8]` that expose byteorder methods.
 pub trait BytesExt<'a> {
     fn read_u8(&mut self) -> Result<u8, Error>;
     fn read_u16<B: ByteOrder>(&mut self) -> Result<u16, Error>;
     fn read_u32<B: ByteOrder>(&mut self) -> Result<u32, Error>;
     fn read_u64<B: ByteOrder>(&mut self) -> Result<u64, Error>;
+
+    /// Reinterprets the next `size_of::<T>()` bytes of `self` as a `&'a T`, without copying.
+    ///
+    /// `self` will be updated to point past the reinterpreted bytes.
+    ///
+    /// Because this returns a reference directly into `self` rather than an owned `T`, a
+    /// misaligned read cannot fall back to copying the data out: `self` does not contain enough
+    /// bytes, or the bytes are not aligned as required by `T`, are both rejected the same way,
+    /// by returning `Error::Eof` without modifying `self`.
+    fn read_pod<T: Pod>(&mut self) -> Result<&'a T, Error>;
+
+    /// Reinterprets the next `count * size_of::<T>()` bytes of `self` as a `&'a [T]`, without
+    /// copying.
+    ///
+    /// `self` will be updated to point past the reinterpreted bytes.
+    ///
+    /// Because this returns a reference directly into `self` rather than owned data, a misaligned
+    /// read cannot fall back to copying the data out: `self` not containing enough bytes, or the
+    /// bytes not being aligned as required by `T`, are both rejected the same way, by returning
+    /// `Error::Eof` without modifying `self`. This method deliberately has no copying fallback for
+    /// misaligned input: it returns a `&'a [T]` borrowed from `self`, and a borrow can't be
+    /// conjured from a copy, so supporting misaligned input would require a separate, owned-`T`
+    /// returning method rather than a fallback inside this one.
+    fn read_pod_slice<T: Pod>(&mut self, count: usize) -> Result<&'a [T], Error>;
 }
 
 impl<'a> BytesExt<'a> for &'a [u8] {
@@ -435,6 +951,24 @@ impl<'a> BytesExt<'a> for &'a [u8] {
         let arr = self.read_array::<[u8; 8]>()?;
         Ok(B::read_u64(&arr))
     }
+
+    fn read_pod<T: Pod>(&mut self) -> Result<&'a T, Error> {
+        Ok(&self.read_pod_slice::<T>(1)?[0])
+    }
+
+    fn read_pod_slice<T: Pod>(&mut self, count: usize) -> Result<&'a [T], Error> {
+        let size = mem::size_of::<T>().checked_mul(count).ok_or(Error::Eof)?;
+        let mut rest = *self;
+        let bytes = rest.read_slice(size)?;
+        if (bytes.as_ptr() as usize) % mem::align_of::<T>() != 0 {
+            return Err(Error::Eof);
+        }
+        *self = rest;
+
+        // SAFETY: `T: Pod` guarantees any bit pattern is a valid `T`. `bytes` was just checked to
+        // contain exactly `count * size_of::<T>()` properly aligned bytes.
+        Ok(unsafe { slice::from_raw_parts(bytes.as_ptr() as *const T, count) })
+    }
 }
 
 /// Extensions on `&'a [T]`.
@@ -550,3 +1084,333 @@ impl<'a> MutSliceExt<'a> for &'a mut [u8] {
         }
     }
 }
+
+/// `std::io::{Write, Read}` impls for [`ByteWriter`] and [`ByteReader`].
+///
+/// Both clamp to whatever space or data is actually left and return `Ok` with a short count
+/// rather than erroring directly, following `Write`'s and `Read`'s own conventions for running out
+/// of room: calling [`write_all`] past the end of the buffer surfaces `ErrorKind::WriteZero`, and
+/// [`read_exact`] past the end of the data surfaces `ErrorKind::UnexpectedEof`, both raised by the
+/// blanket implementations `std::io` already provides on top of `write`/`read`.
+///
+/// [`ByteWriter`]: struct.ByteWriter.html
+/// [`ByteReader`]: struct.ByteReader.html
+/// [`write_all`]: https://doc.rust-lang.org/std/io/trait.Write.html#method.write_all
+/// [`read_exact`]: https://doc.rust-lang.org/std/io/trait.Read.html#method.read_exact
+#[cfg(feature = "std")]
+mod io {
+    use super::{ByteReader, ByteWriter};
+    use std::io;
+
+    impl<'a> io::Write for ByteWriter<'a> {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.space_left());
+            self.write_slice(&buf[..len])
+                .expect("len was clamped to space_left, so this cannot fail");
+            Ok(len)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> io::Read for ByteReader<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.bytes_left());
+            let slice = self
+                .read_slice(len)
+                .expect("len was clamped to bytes_left, so this cannot fail");
+            buf[..len].copy_from_slice(slice);
+            Ok(len)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::LittleEndian;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Record {
+        a: u16,
+        b: u16,
+    }
+
+    unsafe impl Pod for Record {}
+
+    // `align_of::<Record>() == 2`, but a stack array isn't guaranteed to start at an even
+    // address, so pick whichever of offset 0 or 1 gives the alignment we want to exercise.
+    fn offset_with_alignment(buf: &[u8], aligned: bool) -> usize {
+        let starts_aligned = (buf.as_ptr() as usize) % mem::align_of::<Record>() == 0;
+        if starts_aligned == aligned {
+            0
+        } else {
+            1
+        }
+    }
+
+    #[test]
+    fn read_pod_round_trip() {
+        let mut buf = [0u8; 5];
+        let offset = offset_with_alignment(&buf, true);
+        buf[offset..offset + 4].copy_from_slice(&[0x01, 0x02, 0x03, 0x04]);
+
+        let mut reader: &[u8] = &buf[offset..offset + 4];
+        let record: &Record = reader.read_pod().unwrap();
+        assert_eq!(*record, Record { a: 0x0201, b: 0x0403 });
+        assert!(reader.is_empty());
+    }
+
+    #[test]
+    fn read_pod_rejects_misaligned() {
+        let buf = [0u8; 5];
+        let offset = offset_with_alignment(&buf, false);
+
+        let mut reader: &[u8] = &buf[offset..];
+        assert!(matches!(reader.read_pod::<Record>(), Err(Error::Eof)));
+    }
+
+    #[test]
+    fn byte_reader_reads_primitives_in_order() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let mut r = ByteReader::new(&data);
+
+        assert_eq!(r.read_u8().unwrap(), 1);
+        assert_eq!(r.read_u16::<LittleEndian>().unwrap(), 0x0302);
+        assert_eq!(r.read_u32::<LittleEndian>().unwrap(), 0x0706_0504);
+        assert_eq!(r.read_u64::<LittleEndian>().unwrap(), 0x0F0E_0D0C_0B0A_0908);
+        assert_eq!(r.bytes_left(), 0);
+    }
+
+    #[test]
+    fn byte_reader_peek_does_not_advance() {
+        let data = [10, 20, 30];
+        let mut r = ByteReader::new(&data);
+
+        assert_eq!(r.peek().unwrap(), 10);
+        assert_eq!(r.bytes_left(), 3);
+        r.skip(1).unwrap();
+        assert_eq!(r.peek().unwrap(), 20);
+        assert_eq!(r.bytes_left(), 2);
+    }
+
+    #[test]
+    fn byte_reader_read_slice_rejects_eof_without_advancing() {
+        let data = [1, 2, 3];
+        let mut r = ByteReader::new(&data);
+
+        assert_eq!(r.read_slice(2).unwrap(), &[1, 2]);
+        assert!(matches!(r.read_slice(5), Err(Error::Eof)));
+        assert_eq!(r.bytes_left(), 1);
+        assert_eq!(r.read_slice(1).unwrap(), &[3]);
+    }
+
+    #[test]
+    fn byte_reader_read_array() {
+        let data = [1, 2, 3, 4];
+        let mut r = ByteReader::new(&data);
+
+        let arr: [u8; 3] = r.read_array().unwrap();
+        assert_eq!(arr, [1, 2, 3]);
+        assert_eq!(r.bytes_left(), 1);
+    }
+
+    #[test]
+    fn byte_reader_read_generic_from_bytes() {
+        let data = [42, 7];
+        let mut r = ByteReader::new(&data);
+
+        let v: u8 = r.read().unwrap();
+        assert_eq!(v, 42);
+        assert_eq!(r.bytes_left(), 1);
+    }
+
+    #[test]
+    fn byte_reader_split_off_rejects_eof_without_advancing() {
+        let data = [1, 2, 3, 4, 5];
+        let mut r = ByteReader::new(&data);
+
+        let mut sub = r.split_off(3).unwrap();
+        assert_eq!(r.bytes_left(), 2);
+        assert_eq!(sub.read_slice(3).unwrap(), &[1, 2, 3]);
+        assert!(sub.read_u8().is_err());
+
+        assert!(matches!(r.split_off(10), Err(Error::Eof)));
+        assert_eq!(r.bytes_left(), 2);
+    }
+
+    #[test]
+    fn byte_writer_writer_trait_methods() {
+        let mut buf = [0u8; 6];
+        {
+            let mut w = ByteWriter::new(&mut buf);
+            Writer::write_byte(&mut w, 1).unwrap();
+            Writer::write_slice(&mut w, &[2, 3]).unwrap();
+            w.write_u16::<LittleEndian>(0x0504).unwrap();
+        }
+        assert_eq!(buf, [1, 2, 3, 4, 5, 0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec_writer_grows_instead_of_running_out_of_space() {
+        let mut v: alloc::vec::Vec<u8> = alloc::vec::Vec::new();
+        v.write_byte(1).unwrap();
+        v.write_slice(&[2, 3]).unwrap();
+        v.write_u32::<LittleEndian>(0x0807_0605).unwrap();
+        assert_eq!(v.as_slice(), [1, 2, 3, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn to_bytes_is_generic_over_writer() {
+        let payload: &[u8] = &[9, 8, 7];
+
+        let mut buf = [0u8; 3];
+        {
+            let mut w = ByteWriter::new(&mut buf);
+            payload.to_bytes(&mut w).unwrap();
+        }
+        assert_eq!(buf, [9, 8, 7]);
+
+        let mut v: alloc::vec::Vec<u8> = alloc::vec::Vec::new();
+        payload.to_bytes(&mut v).unwrap();
+        assert_eq!(v.as_slice(), [9, 8, 7]);
+    }
+
+    #[test]
+    fn chain_spills_into_second_buffer() {
+        let mut a = [0u8; 2];
+        let mut b = [0u8; 2];
+        {
+            let wa = ByteWriter::new(&mut a);
+            let wb = ByteWriter::new(&mut b);
+            let mut chain = wa.chain(wb);
+            chain.write_slice(&[1, 2, 3, 4]).unwrap();
+        }
+        assert_eq!(a, [1, 2]);
+        assert_eq!(b, [3, 4]);
+    }
+
+    #[test]
+    fn chain_write_slice_is_atomic_on_failure() {
+        let mut a = [0u8; 2];
+        let mut b = [0u8; 1];
+        {
+            let wa = ByteWriter::new(&mut a);
+            let wb = ByteWriter::new(&mut b);
+            let mut chain = wa.chain(wb);
+            assert!(matches!(chain.write_slice(&[1, 2, 3, 4]), Err(Error::Eof)));
+        }
+        // Neither buffer was touched by the failed write.
+        assert_eq!(a, [0, 0]);
+        assert_eq!(b, [0]);
+    }
+
+    #[test]
+    fn limit_caps_writes_below_underlying_capacity() {
+        let mut buf = [0u8; 8];
+        {
+            let w = ByteWriter::new(&mut buf);
+            let mut limited = w.limit(2);
+            limited.write_slice(&[1, 2]).unwrap();
+            assert!(matches!(limited.write_byte(3), Err(Error::Eof)));
+        }
+        assert_eq!(&buf[..2], [1, 2]);
+    }
+
+    #[test]
+    fn take_caps_reads_below_underlying_data() {
+        let data = [1, 2, 3, 4];
+        let r = ByteReader::new(&data);
+        let mut limited = r.take(2);
+
+        assert_eq!(limited.bytes_left(), 2);
+        assert_eq!(limited.read_slice(2).unwrap(), &[1, 2]);
+        assert!(matches!(limited.read_slice(1), Err(Error::Eof)));
+        // The limit stopped `limited` two bytes early, but the underlying reader still has them.
+        assert_eq!(limited.into_inner().bytes_left(), 2);
+    }
+
+    #[test]
+    fn take_read_generic_respects_limit() {
+        let data = [1, 2, 3, 4];
+        let r = ByteReader::new(&data);
+        let mut limited = r.take(1);
+
+        let v: u8 = limited.read().unwrap();
+        assert_eq!(v, 1);
+        assert_eq!(limited.limit_left(), 0);
+        assert!(matches!(limited.read::<u8>(), Err(Error::Eof)));
+    }
+
+    #[test]
+    fn write_length_prefixed_backfills_len() {
+        let mut buf = [0u8; 8];
+        {
+            let mut w = ByteWriter::new(&mut buf);
+            w.write_length_prefixed::<LittleEndian, _>(LenWidth::U8, |inner| {
+                inner.write_slice(&[9, 9, 9])
+            })
+            .unwrap();
+        }
+        assert_eq!(&buf[..4], [3, 9, 9, 9]);
+    }
+
+    #[test]
+    fn write_length_prefixed_rejects_len_that_overflows_width() {
+        // 256 bytes of payload can't be expressed in a `LenWidth::U8` length field.
+        let mut buf = [0u8; 300];
+        let mut w = ByteWriter::new(&mut buf);
+        let space_before = w.space_left();
+
+        let result = w.write_length_prefixed::<LittleEndian, _>(LenWidth::U8, |inner| {
+            inner.write_slice(&[0u8; 256])
+        });
+
+        assert!(matches!(result, Err(Error::Eof)));
+        // `self` is left unmodified on error, same as every other fallible method on this type.
+        assert_eq!(w.space_left(), space_before);
+    }
+
+    #[test]
+    fn write_length_prefixed_rejects_failing_closure_without_advancing() {
+        let mut buf = [0u8; 8];
+        let mut w = ByteWriter::new(&mut buf);
+        let space_before = w.space_left();
+
+        let result = w.write_length_prefixed::<LittleEndian, _>(LenWidth::U16, |inner| {
+            inner.write_slice(&[1, 2, 3])?;
+            Err(Error::Eof)
+        });
+
+        assert!(result.is_err());
+        assert_eq!(w.space_left(), space_before);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn byte_writer_write_all_maps_eof_to_write_zero() {
+        use std::io::Write;
+
+        let mut buf = [0u8; 2];
+        let mut w = ByteWriter::new(&mut buf);
+        let err = w.write_all(&[1, 2, 3]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn byte_reader_read_exact_maps_eof_to_unexpected_eof() {
+        use std::io::Read;
+
+        let data = [1u8, 2];
+        let mut r = ByteReader::new(&data);
+        let mut out = [0u8; 3];
+        let err = r.read_exact(&mut out).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}